@@ -4,76 +4,198 @@ use std::simd::{prelude::*, LaneCount, SimdElement, StdFloat, SupportedLaneCount
 use num_traits::Float;
 use rayon::prelude::*;
 
-pub fn cartesian_elementwise<'a, T>(lhs_x: T, lhs_y: T, rhs_x: T, rhs_y: T) -> T
+use crate::metric::{Euclidean, Metric};
+
+pub fn cartesian_elementwise_with<M, T>(lhs_x: T, lhs_y: T, rhs_x: T, rhs_y: T) -> T
 where
-    T: Float + Copy,
+    M: Metric<T>,
+    T: Float + SimdElement,
 {
     let diff_x = rhs_x - lhs_x;
     let diff_y = rhs_y - lhs_y;
-    (diff_x * diff_x + diff_y * diff_y).sqrt()
+    M::accumulate(diff_x, diff_y)
 }
 
-pub fn cartesian_simd<T, const N: usize>(
+/// [`cartesian_elementwise_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_elementwise<T>(lhs_x: T, lhs_y: T, rhs_x: T, rhs_y: T) -> T
+where
+    T: Float + SimdElement,
+{
+    cartesian_elementwise_with::<Euclidean, T>(lhs_x, lhs_y, rhs_x, rhs_y)
+}
+
+pub fn cartesian_simd_with<M, T, const N: usize>(
     lhs_x: Simd<T, N>,
     lhs_y: Simd<T, N>,
     rhs_x: Simd<T, N>,
     rhs_y: Simd<T, N>,
 ) -> Simd<T, N>
 where
+    M: Metric<T>,
     T: SimdElement,
     LaneCount<N>: SupportedLaneCount,
-    Simd<T, N>:
-        StdFloat + Add<Output = Simd<T, N>> + Sub<Output = Simd<T, N>> + Mul<Output = Simd<T, N>>,
+    Simd<T, N>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, N>>
+        + Sub<Output = Simd<T, N>>
+        + Mul<Output = Simd<T, N>>,
 {
     let diff_x = rhs_x - lhs_x;
     let diff_y = rhs_y - lhs_y;
-    (diff_x.mul_add(diff_x, diff_y * diff_y)).sqrt()
+    M::accumulate_simd(diff_x, diff_y)
 }
 
-pub fn cartesian_seq_simd<T>(
+/// [`cartesian_simd_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_simd<T, const N: usize>(
+    lhs_x: Simd<T, N>,
+    lhs_y: Simd<T, N>,
+    rhs_x: Simd<T, N>,
+    rhs_y: Simd<T, N>,
+) -> Simd<T, N>
+where
+    T: Float + SimdElement,
+    LaneCount<N>: SupportedLaneCount,
+    Simd<T, N>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, N>>
+        + Sub<Output = Simd<T, N>>
+        + Mul<Output = Simd<T, N>>,
+{
+    cartesian_simd_with::<Euclidean, T, N>(lhs_x, lhs_y, rhs_x, rhs_y)
+}
+
+/// [`cartesian_seq_simd_with`] generalized over the lane count `N`.
+///
+/// Candidates are walked with `as_chunks::<N>()` rather than
+/// `chunks_exact(N).from_slice(..)`, so each chunk arrives as a `&[T; N]`
+/// whose length is encoded in the type; building the lane with
+/// `Simd::from_array` then can't panic on a short slice and lets the
+/// compiler elide the bounds check `from_slice` would otherwise need.
+pub fn cartesian_seq_simd_with_n<M, T, const N: usize>(
     lhs_x: &[T],
     lhs_y: &[T],
     rhs_x: &[T],
     rhs_y: &[T],
 ) -> Vec<T>
 where
-    T: SimdElement,
+    M: Metric<T>,
+    T: SimdElement + Default,
+    LaneCount<N>: SupportedLaneCount,
+    Simd<T, N>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, N>>
+        + Sub<Output = Simd<T, N>>
+        + Mul<Output = Simd<T, N>>,
+{
+    let (lhs_x_chunks, lhs_x_remainder) = lhs_x.as_chunks::<N>();
+    let (lhs_y_chunks, lhs_y_remainder) = lhs_y.as_chunks::<N>();
+    let (rhs_x_chunks, rhs_x_remainder) = rhs_x.as_chunks::<N>();
+    let (rhs_y_chunks, rhs_y_remainder) = rhs_y.as_chunks::<N>();
+
+    let mut v = lhs_x_chunks
+        .iter()
+        .zip(lhs_y_chunks.iter())
+        .zip(rhs_x_chunks.iter())
+        .zip(rhs_y_chunks.iter())
+        .fold(
+            Vec::with_capacity(lhs_x.len()),
+            |mut v, (((lhs_x, lhs_y), rhs_x), rhs_y)| {
+                let lhs_x_simd = Simd::from_array(*lhs_x);
+                let lhs_y_simd = Simd::from_array(*lhs_y);
+                let rhs_x_simd = Simd::from_array(*rhs_x);
+                let rhs_y_simd = Simd::from_array(*rhs_y);
+
+                let result = cartesian_simd_with::<M, T, N>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd);
+
+                v.extend(result.as_array());
+                v
+            },
+        );
+
+    let remainder = lhs_x_remainder.len();
+    if remainder > 0 {
+        let lhs_x_simd = Simd::<T, N>::load_or_default(lhs_x_remainder);
+        let lhs_y_simd = Simd::<T, N>::load_or_default(lhs_y_remainder);
+        let rhs_x_simd = Simd::<T, N>::load_or_default(rhs_x_remainder);
+        let rhs_y_simd = Simd::<T, N>::load_or_default(rhs_y_remainder);
+
+        let result = cartesian_simd_with::<M, T, N>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd);
+
+        v.extend(&result.as_array()[..remainder]);
+    }
+
+    v
+}
+
+/// [`cartesian_seq_simd_with_n`] fixed to the original 64-lane width.
+pub fn cartesian_seq_simd_with<M, T>(
+    lhs_x: &[T],
+    lhs_y: &[T],
+    rhs_x: &[T],
+    rhs_y: &[T],
+) -> Vec<T>
+where
+    M: Metric<T>,
+    T: SimdElement + Default,
     Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
         + Add<Output = Simd<T, 64>>
         + Sub<Output = Simd<T, 64>>
         + Mul<Output = Simd<T, 64>>,
 {
-    static CHUNKS_LENGTH: usize = 64;
-
-    lhs_x.chunks_exact(CHUNKS_LENGTH)
-    .zip(lhs_y.chunks_exact(CHUNKS_LENGTH))
-    .zip(rhs_x.chunks_exact(CHUNKS_LENGTH))
-    .zip(rhs_y.chunks_exact(CHUNKS_LENGTH))
-    .fold(
-        Vec::with_capacity(lhs_x.len()),
-        |mut v, (((lhs_x, lhs_y), rhs_x), rhs_y)| {
-            let lhs_x_simd = Simd::<T, 64>::from_slice(lhs_x);
-            let lhs_y_simd = Simd::<T, 64>::from_slice(lhs_y);
-            let rhs_x_simd = Simd::<T, 64>::from_slice(rhs_x);
-            let rhs_y_simd = Simd::<T, 64>::from_slice(rhs_y);
+    cartesian_seq_simd_with_n::<M, T, 64>(lhs_x, lhs_y, rhs_x, rhs_y)
+}
 
-            let result = cartesian_simd::<T, 64>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd);
+/// [`cartesian_seq_simd_with_n`] specialized to [`Euclidean`] distance, for
+/// users who want to pick their own lane count `N` (e.g. `8`, `16`, `32`)
+/// instead of being locked to 64.
+pub fn cartesian_seq_simd_n<T, const N: usize>(
+    lhs_x: &[T],
+    lhs_y: &[T],
+    rhs_x: &[T],
+    rhs_y: &[T],
+) -> Vec<T>
+where
+    T: Float + SimdElement + Default,
+    LaneCount<N>: SupportedLaneCount,
+    Simd<T, N>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, N>>
+        + Sub<Output = Simd<T, N>>
+        + Mul<Output = Simd<T, N>>,
+{
+    cartesian_seq_simd_with_n::<Euclidean, T, N>(lhs_x, lhs_y, rhs_x, rhs_y)
+}
 
-            v.extend(result.as_array());
-            v
-        }
-    )
+/// [`cartesian_seq_simd_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_seq_simd<T>(
+    lhs_x: &[T],
+    lhs_y: &[T],
+    rhs_x: &[T],
+    rhs_y: &[T],
+) -> Vec<T>
+where
+    T: Float + SimdElement + Default,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+{
+    cartesian_seq_simd_with::<Euclidean, T>(lhs_x, lhs_y, rhs_x, rhs_y)
 }
 
-pub fn cartesian_par_simd<T>(
+pub fn cartesian_par_simd_with<M, T>(
     lhs_x: &[T],
     lhs_y: &[T],
     rhs_x: &[T],
     rhs_y: &[T],
 ) -> Vec<T>
 where
-    T: SimdElement + Sync + Send,
+    M: Metric<T>,
+    T: SimdElement + Default + Sync + Send,
     Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
         + Add<Output = Simd<T, 64>>
         + Sub<Output = Simd<T, 64>>
         + Mul<Output = Simd<T, 64>>,
@@ -81,7 +203,7 @@ where
 {
     static CHUNKS_LENGTH: usize = 64;
 
-    lhs_x
+    let mut v = lhs_x
         .par_chunks_exact(CHUNKS_LENGTH)
         .zip(lhs_y.par_chunks_exact(CHUNKS_LENGTH))
         .zip(rhs_x.par_chunks_exact(CHUNKS_LENGTH))
@@ -92,7 +214,7 @@ where
             let rhs_x_simd = Simd::<T, 64>::from_slice(rhs_x);
             let rhs_y_simd = Simd::<T, 64>::from_slice(rhs_y);
 
-            let result = cartesian_simd::<T, 64>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd);
+            let result = cartesian_simd_with::<M, T, 64>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd);
 
             Vec::from(result.as_array())
         })
@@ -102,17 +224,122 @@ where
                 v1.extend(v2);
                 v1
             },
-        )
+        );
+
+    let remainder = lhs_x.len() % CHUNKS_LENGTH;
+    if remainder > 0 {
+        let offset = lhs_x.len() - remainder;
+
+        let lhs_x_simd = Simd::<T, 64>::load_or_default(&lhs_x[offset..]);
+        let lhs_y_simd = Simd::<T, 64>::load_or_default(&lhs_y[offset..]);
+        let rhs_x_simd = Simd::<T, 64>::load_or_default(&rhs_x[offset..]);
+        let rhs_y_simd = Simd::<T, 64>::load_or_default(&rhs_y[offset..]);
+
+        let result = cartesian_simd_with::<M, T, 64>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd);
+
+        v.extend(&result.as_array()[..remainder]);
+    }
+
+    v
 }
 
-pub fn cartesian_par_elementwise<T>(
+/// [`cartesian_par_simd_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_par_simd<T>(
+    lhs_x: &[T],
+    lhs_y: &[T],
+    rhs_x: &[T],
+    rhs_y: &[T],
+) -> Vec<T>
+where
+    T: Float + SimdElement + Default + Sync + Send,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+    [T]: ParallelSlice<T>,
+{
+    cartesian_par_simd_with::<Euclidean, T>(lhs_x, lhs_y, rhs_x, rhs_y)
+}
+
+/// Like [`cartesian_par_simd`], but writes each lane's result straight into
+/// the caller-provided `out` buffer via `Simd::copy_to_slice` instead of
+/// collecting per-chunk `Vec`s and concatenating them in `reduce`. Lets
+/// callers reuse a single buffer across repeated queries. `out` must be at
+/// least as long as the inputs.
+pub fn cartesian_par_simd_into_with<M, T>(
+    lhs_x: &[T],
+    lhs_y: &[T],
+    rhs_x: &[T],
+    rhs_y: &[T],
+    out: &mut [T],
+) where
+    M: Metric<T>,
+    T: SimdElement + Default + Sync + Send,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+    [T]: ParallelSlice<T>,
+{
+    static CHUNKS_LENGTH: usize = 64;
+
+    let remainder = lhs_x.len() % CHUNKS_LENGTH;
+    let offset = lhs_x.len() - remainder;
+
+    lhs_x[..offset]
+        .par_chunks_exact(CHUNKS_LENGTH)
+        .zip(lhs_y[..offset].par_chunks_exact(CHUNKS_LENGTH))
+        .zip(rhs_x[..offset].par_chunks_exact(CHUNKS_LENGTH))
+        .zip(rhs_y[..offset].par_chunks_exact(CHUNKS_LENGTH))
+        .zip(out[..offset].par_chunks_exact_mut(CHUNKS_LENGTH))
+        .for_each(|((((lhs_x, lhs_y), rhs_x), rhs_y), out)| {
+            let lhs_x_simd = Simd::<T, 64>::from_slice(lhs_x);
+            let lhs_y_simd = Simd::<T, 64>::from_slice(lhs_y);
+            let rhs_x_simd = Simd::<T, 64>::from_slice(rhs_x);
+            let rhs_y_simd = Simd::<T, 64>::from_slice(rhs_y);
+
+            let result = cartesian_simd_with::<M, T, 64>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd);
+
+            result.copy_to_slice(out);
+        });
+
+    if remainder > 0 {
+        let lhs_x_simd = Simd::<T, 64>::load_or_default(&lhs_x[offset..]);
+        let lhs_y_simd = Simd::<T, 64>::load_or_default(&lhs_y[offset..]);
+        let rhs_x_simd = Simd::<T, 64>::load_or_default(&rhs_x[offset..]);
+        let rhs_y_simd = Simd::<T, 64>::load_or_default(&rhs_y[offset..]);
+
+        let result = cartesian_simd_with::<M, T, 64>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd);
+
+        out[offset..lhs_x.len()].copy_from_slice(&result.as_array()[..remainder]);
+    }
+}
+
+/// [`cartesian_par_simd_into_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_par_simd_into<T>(lhs_x: &[T], lhs_y: &[T], rhs_x: &[T], rhs_y: &[T], out: &mut [T])
+where
+    T: Float + SimdElement + Default + Sync + Send,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+    [T]: ParallelSlice<T>,
+{
+    cartesian_par_simd_into_with::<Euclidean, T>(lhs_x, lhs_y, rhs_x, rhs_y, out)
+}
+
+pub fn cartesian_par_elementwise_with<M, T>(
     lhs_x: &[T],
     lhs_y: &[T],
     rhs_x: &[T],
     rhs_y: &[T],
 ) -> Vec<T>
 where
-    T: Float + Sync + Send,
+    M: Metric<T>,
+    T: Float + SimdElement + Sync + Send,
 {
     static CHUNKS_LENGTH: usize = 128000;
 
@@ -128,7 +355,7 @@ where
                 .zip(rhs_x.iter())
                 .zip(rhs_y.iter())
                 .map(|(((lhs_x, lhs_y), rhs_x), rhs_y)| {
-                    cartesian_elementwise(*lhs_x, *lhs_y, *rhs_x, *rhs_y)
+                    cartesian_elementwise_with::<M, T>(*lhs_x, *lhs_y, *rhs_x, *rhs_y)
                 })
                 .collect::<Vec<T>>()
         })
@@ -141,22 +368,38 @@ where
         )
 }
 
-pub fn cartesian_par_batch_simd<T>(
+/// [`cartesian_par_elementwise_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_par_elementwise<T>(
+    lhs_x: &[T],
+    lhs_y: &[T],
+    rhs_x: &[T],
+    rhs_y: &[T],
+) -> Vec<T>
+where
+    T: Float + SimdElement + Sync + Send,
+{
+    cartesian_par_elementwise_with::<Euclidean, T>(lhs_x, lhs_y, rhs_x, rhs_y)
+}
+
+pub fn cartesian_par_batch_simd_with<M, T>(
     lhs_x: &[T],
     lhs_y: &[T],
     rhs_x: &[T],
     rhs_y: &[T],
 ) -> Vec<T>
 where
-    T: SimdElement + Sync + Send,
+    M: Metric<T>,
+    T: SimdElement + Default + Sync + Send,
     Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
         + Add<Output = Simd<T, 64>>
         + Sub<Output = Simd<T, 64>>
         + Mul<Output = Simd<T, 64>>,
+    [T]: ParallelSlice<T>,
 {
     static CHUNKS_LENGTH: usize = 128000;
 
-    lhs_x
+    let mut v = lhs_x
         .par_chunks_exact(CHUNKS_LENGTH)
         .zip(lhs_y.par_chunks_exact(CHUNKS_LENGTH))
         .zip(rhs_x.par_chunks_exact(CHUNKS_LENGTH))
@@ -174,7 +417,7 @@ where
                     let rhs_x_simd = Simd::<T, SIMD_LENGTH>::from_slice(rhs_x);
                     let rhs_y_simd = Simd::<T, SIMD_LENGTH>::from_slice(rhs_y);
 
-                    cartesian_simd(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd)
+                    cartesian_simd_with::<M, T, SIMD_LENGTH>(lhs_x_simd, lhs_y_simd, rhs_x_simd, rhs_y_simd)
                 })
                 .fold(
                     Vec::with_capacity(lhs_x.len()),
@@ -190,5 +433,296 @@ where
                 v1.extend(v2);
                 v1
             },
+        );
+
+    // The batch-level chunking may itself leave a trailing slice shorter than
+    // `CHUNKS_LENGTH`; hand that tail to `cartesian_par_simd_with`, which
+    // already knows how to mask-load whatever remainder is left under 64
+    // lanes.
+    let remainder = lhs_x.len() % CHUNKS_LENGTH;
+    if remainder > 0 {
+        let offset = lhs_x.len() - remainder;
+        v.extend(cartesian_par_simd_with::<M, T>(
+            &lhs_x[offset..],
+            &lhs_y[offset..],
+            &rhs_x[offset..],
+            &rhs_y[offset..],
+        ));
+    }
+
+    v
+}
+
+/// [`cartesian_par_batch_simd_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_par_batch_simd<T>(
+    lhs_x: &[T],
+    lhs_y: &[T],
+    rhs_x: &[T],
+    rhs_y: &[T],
+) -> Vec<T>
+where
+    T: Float + SimdElement + Default + Sync + Send,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+    [T]: ParallelSlice<T>,
+{
+    cartesian_par_batch_simd_with::<Euclidean, T>(lhs_x, lhs_y, rhs_x, rhs_y)
+}
+
+/// Distances from the point at `xs[i]`/`ys[i]` to every candidate in
+/// `xs[start..]`/`ys[start..]`, broadcasting the query point into a `Simd<T, 64>`
+/// lane via `splat` and streaming the candidates through the same
+/// [`cartesian_simd_with`] kernel the element-wise functions use.
+fn cartesian_cross_row_simd_with<M, T>(xs: &[T], ys: &[T], i: usize, start: usize) -> Vec<T>
+where
+    M: Metric<T>,
+    T: SimdElement + Default,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+{
+    static CHUNKS_LENGTH: usize = 64;
+
+    let candidates_x = &xs[start..];
+    let candidates_y = &ys[start..];
+
+    let lhs_x_simd = Simd::<T, CHUNKS_LENGTH>::splat(xs[i]);
+    let lhs_y_simd = Simd::<T, CHUNKS_LENGTH>::splat(ys[i]);
+
+    let mut row = candidates_x
+        .chunks_exact(CHUNKS_LENGTH)
+        .zip(candidates_y.chunks_exact(CHUNKS_LENGTH))
+        .fold(
+            Vec::with_capacity(candidates_x.len()),
+            |mut row, (rhs_x, rhs_y)| {
+                let rhs_x_simd = Simd::<T, CHUNKS_LENGTH>::from_slice(rhs_x);
+                let rhs_y_simd = Simd::<T, CHUNKS_LENGTH>::from_slice(rhs_y);
+
+                let result = cartesian_simd_with::<M, T, CHUNKS_LENGTH>(
+                    lhs_x_simd,
+                    lhs_y_simd,
+                    rhs_x_simd,
+                    rhs_y_simd,
+                );
+
+                row.extend(result.as_array());
+                row
+            },
+        );
+
+    let remainder = candidates_x.len() % CHUNKS_LENGTH;
+    if remainder > 0 {
+        let offset = candidates_x.len() - remainder;
+
+        let rhs_x_simd = Simd::<T, CHUNKS_LENGTH>::load_or_default(&candidates_x[offset..]);
+        let rhs_y_simd = Simd::<T, CHUNKS_LENGTH>::load_or_default(&candidates_y[offset..]);
+
+        let result = cartesian_simd_with::<M, T, CHUNKS_LENGTH>(
+            lhs_x_simd,
+            lhs_y_simd,
+            rhs_x_simd,
+            rhs_y_simd,
+        );
+
+        row.extend(&result.as_array()[..remainder]);
+    }
+
+    row
+}
+
+/// Full distance matrix between all pairs of points in a single set, flattened
+/// row-major: `out[i * xs.len() + j]` is the distance between point `i` and
+/// point `j`. The matrix is symmetric, but every row is recomputed from
+/// scratch rather than mirrored from the upper triangle; see
+/// [`cartesian_cross_pairs_simd_with`] if only the unique pairs are needed.
+pub fn cartesian_cross_simd_with<M, T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    M: Metric<T>,
+    T: SimdElement + Default,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+{
+    let n = xs.len();
+
+    (0..n).fold(Vec::with_capacity(n * n), |mut v, i| {
+        v.extend(cartesian_cross_row_simd_with::<M, T>(xs, ys, i, 0));
+        v
+    })
+}
+
+/// [`cartesian_cross_simd_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_cross_simd<T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    T: Float + SimdElement + Default,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+{
+    cartesian_cross_simd_with::<Euclidean, T>(xs, ys)
+}
+
+/// Parallel counterpart of [`cartesian_cross_simd_with`], computing one row of
+/// the distance matrix per rayon task.
+pub fn cartesian_cross_par_simd_with<M, T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    M: Metric<T>,
+    T: SimdElement + Default + Sync + Send,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+{
+    let n = xs.len();
+
+    (0..n)
+        .into_par_iter()
+        .map(|i| cartesian_cross_row_simd_with::<M, T>(xs, ys, i, 0))
+        .reduce(
+            || Vec::<T>::with_capacity(n * n),
+            |mut v1, v2| {
+                v1.extend(v2);
+                v1
+            },
         )
-}
\ No newline at end of file
+}
+
+/// [`cartesian_cross_par_simd_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_cross_par_simd<T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    T: Float + SimdElement + Default + Sync + Send,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+{
+    cartesian_cross_par_simd_with::<Euclidean, T>(xs, ys)
+}
+
+/// Unique upper-triangle pairs (`i < j`) of the distance matrix between all
+/// points in a single set, in the same order as `itertools::combinations`
+/// would yield `(i, j)` index pairs. Avoids computing the self distances on
+/// the diagonal and the duplicate lower triangle that [`cartesian_cross_simd_with`]
+/// would otherwise produce.
+pub fn cartesian_cross_pairs_simd_with<M, T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    M: Metric<T>,
+    T: SimdElement + Default,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+{
+    let n = xs.len();
+
+    (0..n).fold(Vec::with_capacity(n * (n.saturating_sub(1)) / 2), |mut v, i| {
+        v.extend(cartesian_cross_row_simd_with::<M, T>(xs, ys, i, i + 1));
+        v
+    })
+}
+
+/// [`cartesian_cross_pairs_simd_with`] specialized to [`Euclidean`] distance.
+pub fn cartesian_cross_pairs_simd<T>(xs: &[T], ys: &[T]) -> Vec<T>
+where
+    T: Float + SimdElement + Default,
+    Simd<T, 64>: StdFloat
+        + SimdFloat<Scalar = T>
+        + Add<Output = Simd<T, 64>>
+        + Sub<Output = Simd<T, 64>>
+        + Mul<Output = Simd<T, 64>>,
+{
+    cartesian_cross_pairs_simd_with::<Euclidean, T>(xs, ys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-9, "{a} != {e}");
+        }
+    }
+
+    #[test]
+    fn seq_simd_matches_elementwise_on_remainder_tail() {
+        // 70 is not a multiple of 64, exercising the masked remainder load.
+        let n = 70;
+        let lhs_x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let lhs_y: Vec<f64> = (0..n).map(|i| (i * 2) as f64).collect();
+        let rhs_x: Vec<f64> = (0..n).map(|i| (n - i) as f64).collect();
+        let rhs_y: Vec<f64> = (0..n).map(|i| i as f64).collect();
+
+        let simd = cartesian_seq_simd(&lhs_x, &lhs_y, &rhs_x, &rhs_y);
+        let expected: Vec<f64> = lhs_x
+            .iter()
+            .zip(lhs_y.iter())
+            .zip(rhs_x.iter())
+            .zip(rhs_y.iter())
+            .map(|(((lx, ly), rx), ry)| cartesian_elementwise(*lx, *ly, *rx, *ry))
+            .collect();
+
+        assert_close(&simd, &expected);
+    }
+
+    #[test]
+    fn cross_simd_matches_known_values() {
+        let xs = [0.0, 3.0, 0.0];
+        let ys = [0.0, 0.0, 4.0];
+
+        let matrix = cartesian_cross_simd(&xs, &ys);
+        assert_close(&matrix, &[0.0, 3.0, 4.0, 3.0, 0.0, 5.0, 4.0, 5.0, 0.0]);
+    }
+
+    #[test]
+    fn cross_pairs_simd_matches_known_values() {
+        let xs = [0.0, 3.0, 0.0];
+        let ys = [0.0, 0.0, 4.0];
+
+        let pairs = cartesian_cross_pairs_simd(&xs, &ys);
+        assert_close(&pairs, &[3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn par_simd_into_matches_par_simd() {
+        let n = 200;
+        let lhs_x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let lhs_y: Vec<f64> = (0..n).map(|i| (i * 2) as f64).collect();
+        let rhs_x: Vec<f64> = (0..n).map(|i| (n - i) as f64).collect();
+        let rhs_y: Vec<f64> = (0..n).map(|i| i as f64).collect();
+
+        let expected = cartesian_par_simd(&lhs_x, &lhs_y, &rhs_x, &rhs_y);
+
+        let mut actual = vec![0.0; n];
+        cartesian_par_simd_into(&lhs_x, &lhs_y, &rhs_x, &rhs_y, &mut actual);
+
+        assert_close(&actual, &expected);
+    }
+
+    #[test]
+    fn seq_simd_n_matches_seq_simd() {
+        let n = 130;
+        let lhs_x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let lhs_y: Vec<f64> = (0..n).map(|i| (i * 2) as f64).collect();
+        let rhs_x: Vec<f64> = (0..n).map(|i| (n - i) as f64).collect();
+        let rhs_y: Vec<f64> = (0..n).map(|i| i as f64).collect();
+
+        let expected = cartesian_seq_simd(&lhs_x, &lhs_y, &rhs_x, &rhs_y);
+        let actual = cartesian_seq_simd_n::<f64, 8>(&lhs_x, &lhs_y, &rhs_x, &rhs_y);
+
+        assert_close(&actual, &expected);
+    }
+}
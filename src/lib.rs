@@ -1,6 +1,7 @@
 #![feature(portable_simd)]
 
 pub mod cartesian;
+pub mod metric;
 
 #[cfg(test)]
 mod tests {